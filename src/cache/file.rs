@@ -0,0 +1,142 @@
+use super::Cache;
+use crate::Error;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Caches versions as files under a directory, one file per key.
+pub struct FileCache {
+    dir: PathBuf,
+}
+
+impl FileCache {
+    /// Creates a cache backed by files under `dir`. The directory is created
+    /// lazily, the first time something is written to it.
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    pub(crate) fn default_dir() -> Result<PathBuf, Error> {
+        let project_dir = directories::ProjectDirs::from("", "", "update-informer-rs")
+            .map_or(Err("Unable to find cache directory"), Ok)?;
+
+        Ok(project_dir.cache_dir().to_path_buf())
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(key)
+    }
+}
+
+impl Cache for FileCache {
+    fn last_modified(&self, key: &str, placeholder: &str) -> Result<Duration, Error> {
+        let path = self.path(key);
+
+        let metadata = match fs::metadata(&path) {
+            Ok(meta) => meta,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                self.write_version(key, placeholder)?;
+                return Ok(Duration::default());
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(metadata.modified()?.elapsed().unwrap_or_default())
+    }
+
+    fn get_version(&self, key: &str) -> Result<String, Error> {
+        Ok(fs::read_to_string(self.path(key))?)
+    }
+
+    fn write_version(&self, key: &str, version: &str) -> Result<(), Error> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(key), version)?;
+        Ok(())
+    }
+
+    fn recreate(&self, key: &str, version: &str) -> Result<(), Error> {
+        let path = self.path(key);
+        if path.exists() {
+            fs::remove_file(&path)?;
+        }
+        self.write_version(key, version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::within_test_dir;
+
+    fn cache_and_key(path: &std::path::Path) -> (FileCache, String) {
+        let dir = path.parent().expect("test path has a parent").to_path_buf();
+        let key = path
+            .file_name()
+            .expect("test path has a file name")
+            .to_str()
+            .expect("test path is valid UTF-8")
+            .to_string();
+
+        (FileCache::new(dir), key)
+    }
+
+    #[test]
+    fn last_modified_file_not_exists_test() {
+        within_test_dir(|path| {
+            let (cache, key) = cache_and_key(&path);
+
+            let last_modified = cache.last_modified(&key, "0.1.0");
+            assert!(last_modified.is_ok());
+            assert!(last_modified.unwrap().is_zero());
+
+            let version = fs::read_to_string(&path).expect("read test file");
+            assert_eq!(version, "0.1.0");
+        });
+    }
+
+    #[test]
+    fn last_modified_file_exists_test() {
+        within_test_dir(|path| {
+            fs::write(&path, "0.1.0").expect("creates test file");
+            let (cache, key) = cache_and_key(&path);
+
+            let last_modified = cache.last_modified(&key, "0.1.0");
+            assert!(last_modified.is_ok());
+            assert!(!last_modified.unwrap().is_zero());
+        });
+    }
+
+    #[test]
+    fn write_and_get_version_test() {
+        within_test_dir(|path| {
+            let (cache, key) = cache_and_key(&path);
+
+            cache.write_version(&key, "1.0.0").expect("write version");
+            assert_eq!(cache.get_version(&key).expect("read version"), "1.0.0");
+
+            cache.write_version(&key, "2.0.0").expect("overwrite version");
+            assert_eq!(cache.get_version(&key).expect("read version"), "2.0.0");
+        });
+    }
+
+    #[test]
+    fn recreate_test() {
+        within_test_dir(|path| {
+            fs::write(&path, "0.1.0").expect("creates test file");
+            let (cache, key) = cache_and_key(&path);
+
+            cache.recreate(&key, "1.0.0").expect("recreate");
+            assert_eq!(cache.get_version(&key).expect("read version"), "1.0.0");
+        });
+    }
+
+    #[test]
+    fn get_version_file_not_exists_test() {
+        within_test_dir(|path| {
+            let (cache, key) = cache_and_key(&path);
+
+            assert!(cache.get_version(&key).is_err());
+        });
+    }
+}
@@ -0,0 +1,30 @@
+use crate::Error;
+use std::time::Duration;
+
+mod file;
+
+pub use file::FileCache;
+
+/// Storage backing the version check's cache.
+///
+/// The default [`FileCache`] persists to a file under the OS cache
+/// directory. Embedders that run in read-only or sandboxed environments, or
+/// tests that don't want to touch the real filesystem, can supply their own
+/// implementation via [`UpdateInformer::cache`](crate::UpdateInformer::cache).
+pub trait Cache {
+    /// How long ago `key` was last written. If `key` has never been
+    /// written, implementations should write `placeholder` now and return
+    /// `Duration::default()`, so a freshly created entry isn't immediately
+    /// treated as stale.
+    fn last_modified(&self, key: &str, placeholder: &str) -> Result<Duration, Error>;
+
+    /// Reads the value stored for `key`.
+    fn get_version(&self, key: &str) -> Result<String, Error>;
+
+    /// Overwrites the value stored for `key`.
+    fn write_version(&self, key: &str, version: &str) -> Result<(), Error>;
+
+    /// Clears whatever is stored for `key` and writes `version`, as if the
+    /// entry were freshly created.
+    fn recreate(&self, key: &str, version: &str) -> Result<(), Error>;
+}
@@ -0,0 +1,325 @@
+use crate::cache::{Cache, FileCache};
+use crate::registry::Registry;
+use crate::version::{Bump, UpdateVersion};
+use crate::{Error, Package};
+use std::time::Duration;
+
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(60 * 60 * 24);
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+const VERSION_KEY_SUFFIX: &str = "latest-version";
+const AVAILABLE_VERSIONS_KEY_SUFFIX: &str = "available-versions";
+const NOT_FETCHED_PLACEHOLDER: &str = "";
+
+/// Checks whether a newer version of a package is available.
+///
+/// Brought into scope alongside [`UpdateInformer`] so `.check_version()` can
+/// be called on the value returned by [`crate::new`].
+pub trait Check {
+    /// Returns the latest version available, if it is newer than the
+    /// current one and, when a [`constraint`](UpdateInformer::constraint) is
+    /// set, satisfies it. The result carries a [`Bump`] classifying how the
+    /// new version differs from the current one.
+    fn check_version(&self) -> Result<Option<UpdateVersion>, Error>;
+
+    /// Returns every version published for the package, semver-sorted in
+    /// ascending order, instead of collapsing to a single "latest". Yanked
+    /// versions are left out, as are pre-releases unless
+    /// [`allow_prerelease`](UpdateInformer::allow_prerelease) is set; a
+    /// [`constraint`](UpdateInformer::constraint) is not applied.
+    fn available_versions(&self) -> Result<Vec<semver::Version>, Error>;
+}
+
+/// Configures and performs a version check against a [`Registry`].
+pub struct UpdateInformer<'a, R: Registry> {
+    registry: R,
+    pkg: Package<'a>,
+    current_version: &'a str,
+    interval: Duration,
+    timeout: Duration,
+    constraint: Option<semver::VersionReq>,
+    allow_prerelease: bool,
+    cache: Option<Box<dyn Cache>>,
+}
+
+impl<'a, R: Registry> UpdateInformer<'a, R> {
+    pub(crate) fn new(registry: R, pkg: Package<'a>, current_version: &'a str) -> Self {
+        Self {
+            registry,
+            pkg,
+            current_version,
+            interval: DEFAULT_INTERVAL,
+            timeout: DEFAULT_TIMEOUT,
+            constraint: None,
+            allow_prerelease: false,
+            cache: None,
+        }
+    }
+
+    /// Overrides how long a cached "latest version" is considered fresh
+    /// before the registry is queried again. Defaults to 24 hours.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Overrides the HTTP request timeout used when contacting the registry.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Restricts reported updates to versions satisfying `constraint`, e.g.
+    /// `"^1.2"`, so a user pinned to the `1.x` line isn't notified about a
+    /// `2.0` major bump. An empty string or `"*"` matches every version,
+    /// which is the default. Partial specs such as `"1.2"` are accepted and
+    /// treated as `"^1.2"`, the same way Cargo resolves a dependency
+    /// requirement with no operator.
+    pub fn constraint(mut self, constraint: &str) -> Result<Self, Error> {
+        let constraint = constraint.trim();
+        // `VersionReq::STAR` rejects pre-release versions, so "" and "*" are
+        // stored as no constraint at all to behave exactly like the default.
+        self.constraint = if constraint.is_empty() || constraint == "*" {
+            None
+        } else {
+            Some(semver::VersionReq::parse(constraint)?)
+        };
+
+        Ok(self)
+    }
+
+    /// Allows pre-release versions (e.g. `2.0.0-rc.1`) to be reported as an
+    /// update. By default pre-releases are never surfaced, matching how most
+    /// users expect "latest" to mean "latest stable".
+    pub fn allow_prerelease(mut self, allow_prerelease: bool) -> Self {
+        self.allow_prerelease = allow_prerelease;
+        self
+    }
+
+    /// Supplies a custom [`Cache`] instead of the default [`FileCache`], e.g.
+    /// an in-memory cache for tests or long-running daemons, or a store
+    /// suited to a read-only/sandboxed environment.
+    pub fn cache(mut self, cache: impl Cache + 'static) -> Self {
+        self.cache = Some(Box::new(cache));
+        self
+    }
+
+    fn matches(&self, version: &semver::Version) -> bool {
+        self.constraint
+            .as_ref()
+            .map_or(true, |req| req.matches(version))
+    }
+
+    fn cache_key(&self, suffix: &str) -> String {
+        match self.pkg.owner {
+            Some(owner) => format!("{}-{}-{}-{}", R::NAME, owner, self.pkg.name, suffix),
+            None => format!("{}-{}-{}", R::NAME, self.pkg.name, suffix),
+        }
+    }
+
+    fn with_cache<T>(&self, f: impl FnOnce(&dyn Cache) -> Result<T, Error>) -> Result<T, Error> {
+        match &self.cache {
+            Some(cache) => f(cache.as_ref()),
+            None => f(&FileCache::new(FileCache::default_dir()?)),
+        }
+    }
+}
+
+impl<R: Registry> Check for UpdateInformer<'_, R> {
+    fn check_version(&self) -> Result<Option<UpdateVersion>, Error> {
+        let current = semver::Version::parse(self.current_version)?;
+        let key = self.cache_key(VERSION_KEY_SUFFIX);
+
+        let raw_version = self.with_cache(|cache| {
+            if cache.last_modified(&key, self.current_version)? > self.interval {
+                let versions = self
+                    .registry
+                    .versions(&self.pkg, self.timeout, self.allow_prerelease)?;
+                let latest = versions.into_iter().filter(|v| self.matches(v)).max();
+
+                let raw_version = match &latest {
+                    Some(version) => version.to_string(),
+                    None => self.current_version.to_string(),
+                };
+
+                cache.write_version(&key, &raw_version)?;
+                Ok(raw_version)
+            } else {
+                cache.get_version(&key)
+            }
+        })?;
+
+        let latest = semver::Version::parse(&raw_version)?;
+
+        Ok(if latest > current {
+            Some(UpdateVersion {
+                bump: Bump::between(&current, &latest),
+                version: latest,
+            })
+        } else {
+            None
+        })
+    }
+
+    fn available_versions(&self) -> Result<Vec<semver::Version>, Error> {
+        let key = self.cache_key(AVAILABLE_VERSIONS_KEY_SUFFIX);
+
+        // Unlike `check_version`, there is no "don't nag on first run" value
+        // in priming this cache with a placeholder: a caller asking for
+        // every available version wants the real list back immediately, not
+        // just the version they already have. So the not-yet-fetched
+        // placeholder is an empty string, which a real (non-empty) version
+        // list can never equal, and we fetch whenever we see it.
+        let raw_versions = self.with_cache(|cache| {
+            let last_modified = cache.last_modified(&key, NOT_FETCHED_PLACEHOLDER)?;
+            let cached = cache.get_version(&key)?;
+
+            if last_modified > self.interval || cached == NOT_FETCHED_PLACEHOLDER {
+                let mut versions = self
+                    .registry
+                    .versions(&self.pkg, self.timeout, self.allow_prerelease)?;
+                versions.sort();
+
+                let raw_versions = versions
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                cache.write_version(&key, &raw_versions)?;
+                Ok(raw_versions)
+            } else {
+                Ok(cached)
+            }
+        })?;
+
+        if raw_versions.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        raw_versions
+            .split(',')
+            .map(semver::Version::parse)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeRegistry(Vec<&'static str>);
+
+    impl Registry for FakeRegistry {
+        const NAME: &'static str = "fake";
+
+        fn versions(
+            &self,
+            _pkg: &Package,
+            _timeout: Duration,
+            _allow_prerelease: bool,
+        ) -> Result<Vec<semver::Version>, Error> {
+            Ok(self
+                .0
+                .iter()
+                .map(|v| semver::Version::parse(v).unwrap())
+                .collect())
+        }
+    }
+
+    /// Always reports the cache as stale, so `check_version`/`available_versions`
+    /// hit `FakeRegistry` on every call instead of exercising the caching
+    /// behavior already covered by the `cache` module's own tests.
+    struct AlwaysStaleCache;
+
+    impl Cache for AlwaysStaleCache {
+        fn last_modified(&self, _key: &str, _placeholder: &str) -> Result<Duration, Error> {
+            Ok(Duration::MAX)
+        }
+
+        fn get_version(&self, _key: &str) -> Result<String, Error> {
+            Ok(String::new())
+        }
+
+        fn write_version(&self, _key: &str, _version: &str) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn recreate(&self, _key: &str, _version: &str) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    fn informer(versions: Vec<&'static str>, current: &str) -> UpdateInformer<'_, FakeRegistry> {
+        UpdateInformer::new(FakeRegistry(versions), Package::new("repo"), current).cache(AlwaysStaleCache)
+    }
+
+    #[test]
+    fn empty_constraint_matches_everything_test() {
+        let update = informer(vec!["1.0.0", "2.0.0"], "1.0.0")
+            .constraint("")
+            .unwrap()
+            .check_version()
+            .unwrap();
+
+        assert_eq!(update.unwrap().version, semver::Version::parse("2.0.0").unwrap());
+    }
+
+    #[test]
+    fn star_constraint_matches_everything_test() {
+        let update = informer(vec!["1.0.0", "2.0.0"], "1.0.0")
+            .constraint("*")
+            .unwrap()
+            .check_version()
+            .unwrap();
+
+        assert_eq!(update.unwrap().version, semver::Version::parse("2.0.0").unwrap());
+    }
+
+    #[test]
+    fn partial_constraint_is_treated_as_caret_test() {
+        // "1.2" is short for "^1.2", i.e. ">=1.2.0, <2.0.0" (same as Cargo's
+        // own dependency requirements), so 1.3.0 satisfies it but 2.0.0 doesn't.
+        let update = informer(vec!["1.1.9", "1.2.5", "1.3.0", "2.0.0"], "1.2.0")
+            .constraint("1.2")
+            .unwrap()
+            .check_version()
+            .unwrap();
+
+        assert_eq!(update.unwrap().version, semver::Version::parse("1.3.0").unwrap());
+    }
+
+    #[test]
+    fn constraint_matching_nothing_yields_none_test() {
+        let update = informer(vec!["2.0.0", "2.1.0"], "1.0.0")
+            .constraint("^1")
+            .unwrap()
+            .check_version()
+            .unwrap();
+
+        assert!(update.is_none());
+    }
+
+    #[test]
+    fn no_update_when_current_is_already_latest_test() {
+        let update = informer(vec!["1.0.0"], "1.0.0").check_version().unwrap();
+
+        assert!(update.is_none());
+    }
+
+    #[test]
+    fn available_versions_returns_the_full_semver_sorted_list_test() {
+        let versions = informer(vec!["2.0.0", "1.0.0", "1.5.0"], "1.0.0")
+            .available_versions()
+            .unwrap();
+
+        assert_eq!(
+            versions,
+            vec![
+                semver::Version::parse("1.0.0").unwrap(),
+                semver::Version::parse("1.5.0").unwrap(),
+                semver::Version::parse("2.0.0").unwrap(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+
+/// The error type returned by this crate.
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    Http(Box<ureq::Error>),
+    SerdeJson(serde_json::Error),
+    Semver(semver::Error),
+    Other(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "IO error: {}", e),
+            Error::Http(e) => write!(f, "HTTP request failed: {}", e),
+            Error::SerdeJson(e) => write!(f, "failed to parse registry response: {}", e),
+            Error::Semver(e) => write!(f, "invalid version or constraint: {}", e),
+            Error::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<ureq::Error> for Error {
+    fn from(e: ureq::Error) -> Self {
+        Error::Http(Box::new(e))
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::SerdeJson(e)
+    }
+}
+
+impl From<semver::Error> for Error {
+    fn from(e: semver::Error) -> Self {
+        Error::Semver(e)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(msg: &str) -> Self {
+        Error::Other(msg.to_string())
+    }
+}
@@ -0,0 +1,9 @@
+use crate::Error;
+use std::time::Duration;
+
+/// Performs a blocking `GET` request and returns the response body.
+pub(crate) fn get(url: &str, timeout: Duration) -> Result<String, Error> {
+    let body = ureq::get(url).timeout(timeout).call()?.into_string()?;
+
+    Ok(body)
+}
@@ -0,0 +1,37 @@
+//! Checks whether a newer version of your crate or package is available,
+//! caching the result on disk so the check doesn't hit the network on every
+//! run.
+//!
+//! ```no_run
+//! use update_informer::{registry, Check};
+//!
+//! let informer = update_informer::new(registry::Crates, "update-informer", "0.1.0");
+//!
+//! if let Ok(Some(update)) = informer.check_version() {
+//!     println!("New version is available: {} ({} bump)", update.version, update.bump);
+//! }
+//! ```
+
+pub mod cache;
+mod check;
+mod error;
+mod http_client;
+mod package;
+pub mod registry;
+mod version;
+
+#[cfg(test)]
+mod test_helper;
+
+pub use check::{Check, UpdateInformer};
+pub use error::Error;
+pub use package::Package;
+pub use semver::Version;
+pub use version::{Bump, UpdateVersion};
+
+use registry::Registry;
+
+/// Creates an [`UpdateInformer`] for `name` at `version`, checked against `registry`.
+pub fn new<'a, R: Registry>(registry: R, name: &'a str, version: &'a str) -> UpdateInformer<'a, R> {
+    UpdateInformer::new(registry, Package::new(name), version)
+}
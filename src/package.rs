@@ -0,0 +1,35 @@
+use std::fmt;
+
+/// A package to check for updates.
+///
+/// Most registries (crates.io, PyPI) only need a package name, but some
+/// (GitHub) list releases under an `owner/repo` pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Package<'a> {
+    pub(crate) owner: Option<&'a str>,
+    pub(crate) name: &'a str,
+}
+
+impl<'a> Package<'a> {
+    /// Creates a package identified only by name, e.g. a crates.io or PyPI package.
+    pub fn new(name: &'a str) -> Self {
+        Self { owner: None, name }
+    }
+
+    /// Creates a package scoped to an owner, e.g. a GitHub `owner/repo` pair.
+    pub fn with_owner(owner: &'a str, name: &'a str) -> Self {
+        Self {
+            owner: Some(owner),
+            name,
+        }
+    }
+}
+
+impl fmt::Display for Package<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.owner {
+            Some(owner) => write!(f, "{}/{}", owner, self.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use super::Registry;
+use crate::{http_client, Error, Package};
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Looks up published versions of a crate on [crates.io](https://crates.io).
+pub struct Crates;
+
+#[derive(Deserialize)]
+struct VersionsResponse {
+    versions: Vec<VersionEntry>,
+}
+
+#[derive(Deserialize)]
+struct VersionEntry {
+    num: String,
+    yanked: bool,
+}
+
+impl Registry for Crates {
+    const NAME: &'static str = "crates";
+
+    fn versions(
+        &self,
+        pkg: &Package,
+        timeout: Duration,
+        allow_prerelease: bool,
+    ) -> Result<Vec<semver::Version>, Error> {
+        let url = format!("{}/api/v1/crates/{}/versions", base_url(), pkg.name);
+        let body = http_client::get(&url, timeout)?;
+        let response: VersionsResponse = serde_json::from_str(&body)?;
+
+        let versions = response
+            .versions
+            .into_iter()
+            .filter(|entry| !entry.yanked)
+            .filter_map(|entry| semver::Version::parse(&entry.num).ok())
+            .filter(|version| allow_prerelease || version.pre.is_empty())
+            .collect();
+
+        Ok(versions)
+    }
+}
+
+#[cfg(not(test))]
+fn base_url() -> String {
+    "https://crates.io".to_string()
+}
+
+#[cfg(test)]
+fn base_url() -> String {
+    mockito::server_url()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::mock_crates;
+
+    #[test]
+    fn versions_skips_yanked_and_prerelease_by_default_test() {
+        let pkg = Package::new("repo");
+        let (_mock, _data) = mock_crates(&pkg, 200, "tests/fixtures/crates_versions.json");
+
+        let versions = Crates.versions(&pkg, Duration::from_secs(5), false).unwrap();
+
+        assert!(versions.iter().all(|v| v.pre.is_empty()));
+        assert_eq!(versions.iter().max().unwrap().to_string(), "1.4.0");
+    }
+
+    #[test]
+    fn versions_can_include_prerelease_test() {
+        let pkg = Package::new("repo");
+        let (_mock, _data) = mock_crates(&pkg, 200, "tests/fixtures/crates_versions.json");
+
+        let versions = Crates.versions(&pkg, Duration::from_secs(5), true).unwrap();
+
+        assert_eq!(versions.iter().max().unwrap().to_string(), "2.0.0-rc.1");
+    }
+}
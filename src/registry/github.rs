@@ -0,0 +1,74 @@
+use super::Registry;
+use crate::{http_client, Error, Package};
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Looks up published releases of a repository on [GitHub](https://github.com).
+pub struct GitHub;
+
+#[derive(Deserialize)]
+struct Release {
+    tag_name: String,
+    draft: bool,
+    prerelease: bool,
+}
+
+impl Registry for GitHub {
+    const NAME: &'static str = "github";
+
+    fn versions(
+        &self,
+        pkg: &Package,
+        timeout: Duration,
+        allow_prerelease: bool,
+    ) -> Result<Vec<semver::Version>, Error> {
+        let url = format!("{}/repos/{}/releases", base_url(), pkg);
+        let body = http_client::get(&url, timeout)?;
+        let releases: Vec<Release> = serde_json::from_str(&body)?;
+
+        let versions = releases
+            .into_iter()
+            .filter(|release| !release.draft)
+            .filter(|release| allow_prerelease || !release.prerelease)
+            .filter_map(|release| semver::Version::parse(release.tag_name.trim_start_matches('v')).ok())
+            .collect();
+
+        Ok(versions)
+    }
+}
+
+#[cfg(not(test))]
+fn base_url() -> String {
+    "https://api.github.com".to_string()
+}
+
+#[cfg(test)]
+fn base_url() -> String {
+    mockito::server_url()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::mock_github;
+
+    #[test]
+    fn versions_skips_drafts_and_prerelease_by_default_test() {
+        let pkg = Package::with_owner("owner", "repo");
+        let (_mock, _data) = mock_github(&pkg, 200, "tests/fixtures/github_releases.json");
+
+        let versions = GitHub.versions(&pkg, Duration::from_secs(5), false).unwrap();
+
+        assert_eq!(versions, vec![semver::Version::parse("1.4.0").unwrap()]);
+    }
+
+    #[test]
+    fn versions_can_include_prerelease_test() {
+        let pkg = Package::with_owner("owner", "repo");
+        let (_mock, _data) = mock_github(&pkg, 200, "tests/fixtures/github_releases.json");
+
+        let versions = GitHub.versions(&pkg, Duration::from_secs(5), true).unwrap();
+
+        assert_eq!(versions.iter().max().unwrap().to_string(), "2.0.0-beta.1");
+    }
+}
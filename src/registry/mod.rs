@@ -0,0 +1,27 @@
+use crate::{Error, Package};
+use std::time::Duration;
+
+mod crates;
+mod github;
+mod pypi;
+
+pub use crates::Crates;
+pub use github::GitHub;
+pub use pypi::PyPI;
+
+/// A source of published version information for a package, e.g. crates.io.
+pub trait Registry {
+    /// Short, stable name used to namespace the on-disk cache file.
+    const NAME: &'static str;
+
+    /// Fetches every version currently published for `pkg`.
+    ///
+    /// Implementations must never return a yanked version. Pre-release
+    /// versions are also excluded unless `allow_prerelease` is `true`.
+    fn versions(
+        &self,
+        pkg: &Package,
+        timeout: Duration,
+        allow_prerelease: bool,
+    ) -> Result<Vec<semver::Version>, Error>;
+}
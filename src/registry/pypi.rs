@@ -0,0 +1,73 @@
+use super::Registry;
+use crate::{http_client, Error, Package};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Looks up published versions of a package on [PyPI](https://pypi.org).
+pub struct PyPI;
+
+#[derive(Deserialize)]
+struct PackageInfo {
+    releases: HashMap<String, serde::de::IgnoredAny>,
+}
+
+impl Registry for PyPI {
+    const NAME: &'static str = "pypi";
+
+    fn versions(
+        &self,
+        pkg: &Package,
+        timeout: Duration,
+        allow_prerelease: bool,
+    ) -> Result<Vec<semver::Version>, Error> {
+        let url = format!("{}/pypi/{}/json", base_url(), pkg.name);
+        let body = http_client::get(&url, timeout)?;
+        let info: PackageInfo = serde_json::from_str(&body)?;
+
+        let versions = info
+            .releases
+            .keys()
+            .filter_map(|num| semver::Version::parse(num).ok())
+            .filter(|version| allow_prerelease || version.pre.is_empty())
+            .collect();
+
+        Ok(versions)
+    }
+}
+
+#[cfg(not(test))]
+fn base_url() -> String {
+    "https://pypi.org".to_string()
+}
+
+#[cfg(test)]
+fn base_url() -> String {
+    mockito::server_url()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::mock_pypi;
+
+    #[test]
+    fn versions_skips_prerelease_by_default_test() {
+        let pkg = Package::new("repo");
+        let (_mock, _data) = mock_pypi(&pkg, 200, "tests/fixtures/pypi_versions.json");
+
+        let versions = PyPI.versions(&pkg, Duration::from_secs(5), false).unwrap();
+
+        assert_eq!(versions.iter().max().unwrap().to_string(), "1.2.0");
+    }
+
+    #[test]
+    fn versions_can_include_prerelease_test() {
+        let pkg = Package::new("repo");
+        let (_mock, _data) = mock_pypi(&pkg, 200, "tests/fixtures/pypi_versions.json");
+
+        let versions = PyPI.versions(&pkg, Duration::from_secs(5), true).unwrap();
+
+        assert_eq!(versions.iter().max().unwrap().to_string(), "2.0.0-rc.1");
+    }
+}
@@ -36,7 +36,7 @@ pub(crate) fn mock_crates(pkg: &Package, status: usize, data_path: &str) -> (Moc
 }
 
 pub(crate) fn mock_github(pkg: &Package, status: usize, data_path: &str) -> (Mock, String) {
-    let mock_path = format!("/repos/{}/releases/latest", pkg);
+    let mock_path = format!("/repos/{}/releases", pkg);
     let data = fs::read_to_string(data_path).expect("read file to string");
 
     (mock_http(&mock_path, status, &data), data)
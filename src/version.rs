@@ -0,0 +1,104 @@
+use std::fmt;
+use std::ops::Deref;
+
+/// How a newer version differs from the current one, following ordinary
+/// semver precedence: the first field that differs decides the bump, and a
+/// change confined to the pre-release tag is [`Bump::Prerelease`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bump {
+    Major,
+    Minor,
+    Patch,
+    Prerelease,
+}
+
+impl Bump {
+    pub(crate) fn between(current: &semver::Version, latest: &semver::Version) -> Self {
+        if current.major != latest.major {
+            Bump::Major
+        } else if current.minor != latest.minor {
+            Bump::Minor
+        } else if current.patch != latest.patch {
+            Bump::Patch
+        } else {
+            Bump::Prerelease
+        }
+    }
+}
+
+impl fmt::Display for Bump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Bump::Major => "major",
+            Bump::Minor => "minor",
+            Bump::Patch => "patch",
+            Bump::Prerelease => "prerelease",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A newer version found by [`Check::check_version`](crate::Check::check_version),
+/// together with how it differs from the current version.
+///
+/// Derefs to the underlying [`semver::Version`], so code that only cares
+/// about the version itself (e.g. `version.to_string()`) keeps working
+/// unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateVersion {
+    pub version: semver::Version,
+    pub bump: Bump,
+}
+
+impl fmt::Display for UpdateVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.version, f)
+    }
+}
+
+impl Deref for UpdateVersion {
+    type Target = semver::Version;
+
+    fn deref(&self) -> &Self::Target {
+        &self.version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bump(current: &str, latest: &str) -> Bump {
+        Bump::between(
+            &semver::Version::parse(current).unwrap(),
+            &semver::Version::parse(latest).unwrap(),
+        )
+    }
+
+    #[test]
+    fn major_bump_test() {
+        assert_eq!(bump("1.2.3", "2.0.0"), Bump::Major);
+    }
+
+    #[test]
+    fn minor_bump_test() {
+        assert_eq!(bump("1.2.3", "1.3.0"), Bump::Minor);
+    }
+
+    #[test]
+    fn patch_bump_test() {
+        assert_eq!(bump("1.2.3", "1.2.4"), Bump::Patch);
+    }
+
+    #[test]
+    fn prerelease_bump_test() {
+        assert_eq!(bump("1.2.3-alpha.1", "1.2.3-beta.1"), Bump::Prerelease);
+    }
+
+    #[test]
+    fn prerelease_to_release_is_prerelease_test() {
+        // 1.2.3-alpha.1 -> 1.2.3 differs only in pre-release/build
+        // metadata, the same major.minor.patch, so it's still `Prerelease`.
+        assert_eq!(bump("1.2.3-alpha.1", "1.2.3"), Bump::Prerelease);
+    }
+}